@@ -0,0 +1,204 @@
+//! An optional drag-and-drop subsystem built on top of the core pointer events.
+//!
+//! Add [`Draggable`] to an entity to let the user pick it up and move it with a pointer: on
+//! [`PointerDragStart`] the entity's parent and transform are recorded and it tracks the active
+//! pointer's location every frame, then on [`PointerDragEnd`] it's either left where it was
+//! dropped or snapped back, depending on whether its own pointer's [`PointerDrop`] landed on a
+//! [`DropTarget`]. The dragged entity keeps its parent throughout; it is not reparented under the
+//! pointer or under the `DropTarget` it lands on.
+
+use bevy::prelude::*;
+use bevy_picking_core::{
+    output::{EventData, EventFrom, EventListenerCommands, IsPointerEvent, PointerDragStart, PointerDragEnd, PointerDrop},
+    pointer::{PointerId, PointerLocation},
+};
+use bevy_picking_input::PickingPluginsSettings;
+
+/// Marks an entity as pick-up-and-move-able by a pointer drag.
+#[derive(Debug, Clone, Copy, Component, Default)]
+pub struct Draggable;
+
+/// Marks an entity that accepts dropped [`Draggable`]s. A drop that doesn't land on a
+/// `DropTarget` snaps the dragged entity back to where it started.
+#[derive(Debug, Clone, Copy, Component, Default)]
+pub struct DropTarget;
+
+/// Recorded on drag start so the entity can be restored once the drag ends, and so
+/// [`follow_pointer`] knows which pointer is actually holding it.
+#[derive(Debug, Clone, Copy, Component)]
+struct DragOrigin {
+    pointer: PointerId,
+    parent: Option<Entity>,
+    transform: Transform,
+}
+
+/// Adds the [`Draggable`]/[`DropTarget`] drag-and-drop behavior.
+pub struct DragPlugin;
+impl Plugin for DragPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<StartDrag>()
+            .add_event::<EndDrag>()
+            .add_event::<Dropped>()
+            .add_system_to_stage(CoreStage::PreUpdate, wire_draggable)
+            .add_system_to_stage(CoreStage::PreUpdate, wire_drop_target)
+            .add_system_to_stage(CoreStage::PreUpdate, start_drag.after(wire_draggable))
+            .add_system_to_stage(CoreStage::Update, follow_pointer)
+            .add_system_to_stage(CoreStage::PostUpdate, end_drag);
+    }
+}
+
+/// Newly-spawned [`Draggable`] entities are automatically wired up to forward the pointer events
+/// this plugin needs, so users only have to add the marker component.
+///
+/// Not gated on [`PickingPluginsSettings::enable_events`]: it's one-shot wiring driven by
+/// `Added<Draggable>`, and a bevy system's change-detection tick advances on every invocation
+/// regardless of an internal early return, so gating it would let entities added while events are
+/// disabled silently fall through `Added<>` and never get wired, even after events come back on.
+/// The forwarded events themselves only exist while dispatch is enabled, so this is already a
+/// no-op in effect when events are off.
+fn wire_draggable(mut commands: Commands, new_draggables: Query<Entity, Added<Draggable>>) {
+    for entity in &new_draggables {
+        commands
+            .entity(entity)
+            .forward_events::<PointerDragStart, StartDrag>()
+            .forward_events::<PointerDragEnd, EndDrag>();
+    }
+}
+
+/// Newly-spawned [`DropTarget`] entities are wired up to forward [`PointerDrop`] - which, per this
+/// crate's dispatch model, fires on the hovered entity the pointer released over, not on the
+/// entity being dragged.
+///
+/// See [`wire_draggable`] for why this isn't gated on `enable_events`.
+fn wire_drop_target(mut commands: Commands, new_targets: Query<Entity, Added<DropTarget>>) {
+    for entity in &new_targets {
+        commands.entity(entity).forward_events::<PointerDrop, Dropped>();
+    }
+}
+
+struct StartDrag {
+    target: Entity,
+    pointer: PointerId,
+}
+impl EventFrom for StartDrag {
+    fn new(event_data: &mut EventData<impl IsPointerEvent>) -> Self {
+        Self {
+            target: event_data.target(),
+            pointer: event_data.pointer(),
+        }
+    }
+}
+
+struct EndDrag(Entity);
+impl EventFrom for EndDrag {
+    fn new(event_data: &mut EventData<impl IsPointerEvent>) -> Self {
+        Self(event_data.target())
+    }
+}
+
+/// A [`PointerDrop`] landing on a [`DropTarget`], tagged with the pointer that dropped it so
+/// [`end_drag`] can scope the check to the dragged entity's own pointer.
+struct Dropped {
+    pointer: PointerId,
+}
+impl EventFrom for Dropped {
+    fn new(event_data: &mut EventData<impl IsPointerEvent>) -> Self {
+        Self {
+            pointer: event_data.pointer(),
+        }
+    }
+}
+
+fn start_drag(
+    picking_settings: Res<PickingPluginsSettings>,
+    mut commands: Commands,
+    mut drag_starts: EventReader<StartDrag>,
+    draggable: Query<(Option<&Parent>, &Transform), With<Draggable>>,
+) {
+    if !picking_settings.enable_events {
+        return;
+    }
+    for StartDrag { target, pointer } in drag_starts.iter() {
+        let Ok((parent, transform)) = draggable.get(*target) else {
+            continue;
+        };
+        commands.entity(*target).insert(DragOrigin {
+            pointer: *pointer,
+            parent: parent.map(|p| p.get()),
+            transform: *transform,
+        });
+    }
+}
+
+/// Each frame, moves every actively-dragged entity to track the one pointer that's holding it
+/// (per its recorded [`DragOrigin::pointer`]), projected into world space through the active
+/// camera, in both 2D and 3D.
+fn follow_pointer(
+    picking_settings: Res<PickingPluginsSettings>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(&Camera, &GlobalTransform, Option<&OrthographicProjection>)>,
+    mut dragged: Query<(&mut Transform, &GlobalTransform, &DragOrigin), With<Draggable>>,
+) {
+    if !picking_settings.enable_events {
+        return;
+    }
+    for (mut transform, global_transform, origin) in &mut dragged {
+        let Some((_, pointer_location)) = pointers.iter().find(|(&id, _)| id == origin.pointer)
+        else {
+            continue;
+        };
+        let Some(location) = &pointer_location.location else {
+            continue;
+        };
+        for (camera, camera_transform, ortho) in &cameras {
+            if camera.target != location.target {
+                continue;
+            }
+            let depth = global_transform.translation().z;
+            // Only 2D cameras carry an `OrthographicProjection` component directly; 3D cameras
+            // take the ray-cast branch below.
+            let world_pos_2d = ortho
+                .and_then(|_| camera.viewport_to_world_2d(camera_transform, location.position));
+            if let Some(world_pos) = world_pos_2d {
+                transform.translation.x = world_pos.x;
+                transform.translation.y = world_pos.y;
+                transform.translation.z = depth;
+            } else if let Some(ray) = camera.viewport_to_world(camera_transform, location.position)
+            {
+                if let Some(distance) = ray.intersect_plane(Vec3::ZERO, Vec3::Y) {
+                    transform.translation = ray.get_point(distance);
+                }
+            }
+        }
+    }
+}
+
+fn end_drag(
+    picking_settings: Res<PickingPluginsSettings>,
+    mut commands: Commands,
+    mut drag_ends: EventReader<EndDrag>,
+    mut drops: EventReader<Dropped>,
+    origins: Query<&DragOrigin>,
+) {
+    if !picking_settings.enable_events {
+        return;
+    }
+    // Pointers whose `PointerDrop` landed on a `DropTarget` this frame, scoped per-pointer so a
+    // `DropTarget` hovered by one pointer doesn't save a drag held by a different one.
+    let dropped_pointers: Vec<PointerId> = drops.iter().map(|Dropped { pointer }| *pointer).collect();
+    for EndDrag(entity) in drag_ends.iter() {
+        let Ok(origin) = origins.get(*entity) else {
+            continue;
+        };
+        if !dropped_pointers.contains(&origin.pointer) {
+            let mut entity_commands = commands.entity(*entity);
+            entity_commands.insert(origin.transform);
+            if let Some(parent) = origin.parent {
+                entity_commands.set_parent(parent);
+            } else {
+                entity_commands.remove_parent();
+            }
+        }
+        commands.entity(*entity).remove::<DragOrigin>();
+    }
+}
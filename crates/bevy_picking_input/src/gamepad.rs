@@ -0,0 +1,94 @@
+use bevy::{input::gamepad::GamepadButtonType, prelude::*};
+use bevy_picking_core::pointer::{Location, PointerLocation, PointerPress};
+
+use crate::PickingPluginsSettings;
+
+/// Drives a [`PointerId::Custom`] software cursor from a single gamepad's sticks and buttons.
+/// Add this to a pointer entity built like `bevy_mod_picking`'s `PointerBundle` (its
+/// `DefaultPointersPlugin` does so for every connected gamepad) to turn that pointer into a
+/// controller-driven cursor.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct GamepadPointerSettings {
+    /// Which gamepad drives this pointer.
+    pub gamepad: Gamepad,
+    /// The stick's X axis; the matching Y axis is inferred from it (e.g. `LeftStickX` pairs with
+    /// `LeftStickY`).
+    pub stick: GamepadAxisType,
+    /// The button that maps to [`PointerPress::primary`] down/up.
+    pub click_button: GamepadButtonType,
+    /// Cursor movement speed, in logical pixels per second per unit of stick deflection.
+    pub speed: f32,
+    /// Stick deflection below this magnitude is ignored.
+    pub deadzone: f32,
+}
+impl Default for GamepadPointerSettings {
+    fn default() -> Self {
+        Self {
+            gamepad: Gamepad { id: 0 },
+            stick: GamepadAxisType::LeftStickX,
+            click_button: GamepadButtonType::South,
+            speed: 1000.0,
+            deadzone: 0.12,
+        }
+    }
+}
+
+/// Moves every [`PointerId::Custom`] pointer that has a [`GamepadPointerSettings`] according to
+/// its gamepad's stick and button state, clamping the result to the primary window, and
+/// translates the configured button into [`PointerPress`] down/up so `Click`, `Drag`, etc. fire
+/// like any other pointer.
+pub fn gamepad_pick_events(
+    picking_settings: Res<PickingPluginsSettings>,
+    time: Res<Time>,
+    windows: Res<Windows>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<Input<GamepadButton>>,
+    mut pointers: Query<(&GamepadPointerSettings, &mut PointerLocation, &mut PointerPress)>,
+) {
+    if !picking_settings.enable_input {
+        return;
+    }
+    let Some(window) = windows.get_primary() else {
+        return;
+    };
+    let bounds = Vec2::new(window.width(), window.height());
+
+    for (settings, mut location, mut press) in &mut pointers {
+        let x = axes
+            .get(GamepadAxis(settings.gamepad, settings.stick))
+            .unwrap_or(0.0);
+        let y = axes
+            .get(GamepadAxis(
+                settings.gamepad,
+                matching_y_axis(settings.stick),
+            ))
+            .unwrap_or(0.0);
+        let stick = Vec2::new(x, y);
+
+        if stick.length() > settings.deadzone {
+            let delta = stick * settings.speed * time.delta_seconds();
+            let position = location
+                .location
+                .as_ref()
+                .map(|l| l.position)
+                .unwrap_or(bounds / 2.0);
+            let clamped = (position + delta).clamp(Vec2::ZERO, bounds);
+            location.location = Some(Location {
+                position: clamped,
+                target: bevy::render::camera::RenderTarget::Window(window.id()),
+            });
+        }
+
+        let button = GamepadButton(settings.gamepad, settings.click_button);
+        press.primary = buttons.pressed(button);
+    }
+}
+
+/// A reasonable "other axis" to pair with a configured stick axis, so a single
+/// [`GamepadPointerSettings::stick`] setting drives both dimensions of cursor movement.
+fn matching_y_axis(stick: GamepadAxisType) -> GamepadAxisType {
+    match stick {
+        GamepadAxisType::RightStickX => GamepadAxisType::RightStickY,
+        _ => GamepadAxisType::LeftStickY,
+    }
+}
@@ -0,0 +1,72 @@
+//! Translates mouse input into [`CursorLocation`](bevy_picking_core::input::CursorLocation)
+//! and [`CursorClick`](bevy_picking_core::input::CursorClick) updates, and gamepad input into
+//! [`PointerLocation`](bevy_picking_core::pointer::PointerLocation) and
+//! [`PointerPress`](bevy_picking_core::pointer::PointerPress) updates, that the picking backends
+//! and focus pass consume.
+
+use bevy::prelude::*;
+
+mod gamepad;
+mod mouse;
+
+pub use gamepad::{gamepad_pick_events, GamepadPointerSettings};
+pub use mouse::mouse_pick_events;
+
+/// Adds mouse and gamepad input to the picking pipeline.
+pub struct InputPlugin;
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputPluginSettings>()
+            .init_resource::<PickingPluginsSettings>()
+            .add_system_to_stage(CoreStage::First, mouse_pick_events)
+            .add_system_to_stage(CoreStage::First, gamepad_pick_events);
+    }
+}
+
+/// Runtime toggle for the picking, hover, and event-dispatch subsystems.
+///
+/// The input systems, the sprite backend, the drag plugin's per-frame systems, and
+/// `DebugEventsPlugin`'s systems check this resource before doing any work, so a game can pause
+/// picking - say, while a menu owns input - without removing or re-adding plugins. Systems that
+/// only react to newly added components or newly connected gamepads are not gated by it.
+pub struct PickingPluginsSettings {
+    /// Toggles whether [`mouse_pick_events`] and [`gamepad_pick_events`] update pointer locations
+    /// and press state at all.
+    pub enable_input: bool,
+    /// Toggles whether picking backends that check it (such as the sprite backend) run their hit
+    /// tests. Hover and focus state updates do not read it.
+    pub enable_picking: bool,
+    /// Toggles whether pointer events (`Over`, `Click`, `Drag`, etc.) are dispatched.
+    pub enable_events: bool,
+}
+impl Default for PickingPluginsSettings {
+    fn default() -> Self {
+        Self {
+            enable_input: true,
+            enable_picking: true,
+            enable_events: true,
+        }
+    }
+}
+
+/// Settings for the input plugin.
+pub struct InputPluginSettings {
+    /// Controls how often cursor state is refreshed.
+    pub mode: UpdateMode,
+}
+impl Default for InputPluginSettings {
+    fn default() -> Self {
+        Self {
+            mode: UpdateMode::EveryFrame,
+        }
+    }
+}
+
+/// Controls how often [`mouse_pick_events`] refreshes cursor state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Refresh every frame, even if no new mouse events arrived.
+    EveryFrame,
+    /// Only refresh when a `CursorMoved` or `CursorLeft` event is received.
+    OnEvent,
+}
@@ -4,59 +4,68 @@ use bevy_picking_core::{
     CursorBundle,
 };
 
-use crate::{InputPluginSettings, UpdateMode};
+use crate::{InputPluginSettings, PickingPluginsSettings, UpdateMode};
 
 /// Updates [`CursorInput`]s to be processed by the picking backend
+///
+/// A single physical mouse can only ever be in one window at a time, but which window that is
+/// changes as the cursor crosses window boundaries, and a window's own `cursor_position()`
+/// remains `Some` even after the cursor has left it. So rather than trusting the first window
+/// that reports a position, this applies the latest `CursorMoved` first, then any `CursorLeft`
+/// for the window that move just landed on - a `CursorLeft` for that window means "now outside
+/// every window", so it has to be able to override an entered/moved-into window from earlier in the
+/// same frame, not just a carried-over location from the previous frame. This correctly tracks
+/// the one window (and its `RenderTarget::Window(id)`) the mouse is currently over, including the
+/// case where the cursor enters and then immediately leaves a window within a single frame.
 pub fn mouse_pick_events(
     mut commands: Commands,
+    picking_settings: Res<PickingPluginsSettings>,
     settings: Res<InputPluginSettings>,
-    windows: Res<Windows>,
-    cursor_move: EventReader<CursorMoved>,
-    cursor_leave: EventReader<CursorLeft>,
+    mut cursor_move: EventReader<CursorMoved>,
+    mut cursor_leave: EventReader<CursorLeft>,
     mut cursor_query: Query<(&CursorId, &mut CursorLocation)>,
 ) {
+    if !picking_settings.enable_input {
+        return;
+    }
     if matches!(settings.mode, UpdateMode::OnEvent)
         && cursor_move.is_empty()
         && cursor_leave.is_empty()
     {
         return;
     }
-    let try_cursor = get_cursor_position(windows);
-    update_cursor(&mut commands, try_cursor, &mut cursor_query);
-}
 
-fn get_cursor_position(windows: Res<Windows>) -> Option<Location> {
-    for window in windows.iter() {
-        if let Some(position) = window.cursor_position() {
-            return Some(Location {
-                position,
-                target: RenderTarget::Window(window.id()),
-            });
-        }
-    }
-    None
+    let Some((_, mut location)) = cursor_query.iter_mut().find(|(&id, _)| id.is_mouse()) else {
+        let mut location = CursorLocation { location: None };
+        apply_cursor_events(&mut location, &mut cursor_move, &mut cursor_leave);
+        commands.spawn_bundle(CursorBundle::new(
+            CursorId::Mouse,
+            location,
+            CursorClick { is_clicked: false },
+        ));
+        return;
+    };
+    apply_cursor_events(&mut location, &mut cursor_move, &mut cursor_leave);
 }
 
-fn update_cursor(
-    commands: &mut Commands,
-    new_location: Option<Location>,
-    cursor_query: &mut Query<(&CursorId, &mut CursorLocation)>,
+fn apply_cursor_events(
+    location: &mut CursorLocation,
+    cursor_move: &mut EventReader<CursorMoved>,
+    cursor_leave: &mut EventReader<CursorLeft>,
 ) {
-    for (&id, mut old_location) in cursor_query.iter_mut() {
-        if !id.is_mouse() {
-            continue;
-        }
-        if old_location.as_ref().location != new_location {
-            old_location.location = new_location;
-            return;
+    if let Some(moved) = cursor_move.iter().last() {
+        location.location = Some(Location {
+            position: moved.position,
+            target: RenderTarget::Window(moved.id),
+        });
+    }
+    for left in cursor_leave.iter() {
+        if location
+            .location
+            .as_ref()
+            .map_or(false, |l| l.target == RenderTarget::Window(left.id))
+        {
+            location.location = None;
         }
     }
-
-    commands.spawn_bundle(CursorBundle::new(
-        CursorId::Mouse,
-        CursorLocation {
-            location: new_location,
-        },
-        CursorClick { is_clicked: false },
-    ));
 }
@@ -0,0 +1,210 @@
+//! A picking backend for 2D [`Sprite`] entities (see below for [`TextureAtlasSprite`]), with an opt-out
+//! per-pixel alpha test so clicks pass through transparent regions of a sprite.
+//!
+//! # How it works
+//!
+//! For every pointer [`Location`](bevy_picking_core::pointer::Location), the cursor is projected
+//! into world space through the 2D camera rendering to that location's [`RenderTarget`]. Every
+//! sprite's world-space AABB is derived from its [`GlobalTransform`], `custom_size`/texture
+//! dimensions, and [`Anchor`]; a hit is reported when the cursor falls inside. When
+//! [`SpriteBackendSettings::alpha_test`] is enabled, the hit point is converted into the sprite's
+//! local pixel coordinate and the texture's alpha channel is sampled at that texel; hits below
+//! [`SpriteBackendSettings::alpha_threshold`] are rejected. Hits are depth-sorted so the
+//! frontmost sprite wins, then pushed into the same [`PointerHits`] pipeline the raycast backend
+//! uses.
+//!
+//! Only entities that carry both a [`Sprite`] and a `Handle<Image>` are tested. When such an
+//! entity also has a [`TextureAtlasSprite`] and a `Handle<TextureAtlas>`, those select its size
+//! and atlas rect, but alpha is still sampled from the entity's own `Handle<Image>`, not from the
+//! atlas texture. Entities with only atlas components (as spawned by a `SpriteSheetBundle`) are
+//! not picked.
+
+use bevy::{
+    prelude::*,
+    render::camera::RenderTarget,
+    sprite::{Anchor, TextureAtlas, TextureAtlasSprite},
+};
+use bevy_picking_core::{
+    backend::{HitData, PointerHits},
+    pointer::{PointerId, PointerLocation},
+};
+use bevy_picking_input::PickingPluginsSettings;
+
+/// Runtime configuration for the sprite picking backend.
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteBackendSettings {
+    /// When `true`, a hit inside a sprite's AABB is only reported if the sampled texel's alpha is
+    /// at or above [`Self::alpha_threshold`]. Disable this if your sprite textures don't have
+    /// (or don't need) an alpha channel; it's a bit of extra work per candidate hit.
+    pub alpha_test: bool,
+    /// The minimum alpha, in `0.0..=1.0`, a texel must have to count as a hit when
+    /// [`Self::alpha_test`] is enabled.
+    pub alpha_threshold: f32,
+}
+
+impl Default for SpriteBackendSettings {
+    fn default() -> Self {
+        Self {
+            alpha_test: true,
+            alpha_threshold: 0.01,
+        }
+    }
+}
+
+/// Adds picking support for `Sprite` entities; see the module docs for how `TextureAtlasSprite`
+/// is handled.
+pub struct SpritePlugin;
+impl Plugin for SpritePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpriteBackendSettings>()
+            .add_system_to_stage(CoreStage::First, sprite_picking);
+    }
+}
+
+/// Casts every pointer against sprites rendered by the camera targeting the pointer's
+/// [`RenderTarget`], depth-sorts the candidates so the frontmost sprite wins, and emits
+/// [`PointerHits`].
+pub fn sprite_picking(
+    picking_settings: Res<PickingPluginsSettings>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    sprites: Query<(
+        Entity,
+        &Sprite,
+        &GlobalTransform,
+        &Handle<Image>,
+        Option<&TextureAtlasSprite>,
+        Option<&Handle<TextureAtlas>>,
+    )>,
+    images: Res<Assets<Image>>,
+    atlases: Res<Assets<TextureAtlas>>,
+    settings: Res<SpriteBackendSettings>,
+    mut output: EventWriter<PointerHits>,
+) {
+    if !picking_settings.enable_picking {
+        return;
+    }
+    for (&pointer_id, pointer_location) in &pointers {
+        let Some(location) = &pointer_location.location else {
+            continue;
+        };
+
+        for (camera_entity, camera, camera_transform) in &cameras {
+            if camera.target != location.target {
+                continue;
+            }
+            let Some(world_pos) = camera.viewport_to_world_2d(camera_transform, location.position)
+            else {
+                continue;
+            };
+
+            let mut hits: Vec<(Entity, HitData)> = sprites
+                .iter()
+                .filter_map(|(entity, sprite, transform, image_handle, atlas_sprite, atlas_handle)| {
+                    let (size, rect) =
+                        sprite_size_and_rect(sprite, atlas_sprite, atlas_handle, &atlases, &images, image_handle)?;
+                    let anchor = sprite.anchor.as_vec();
+                    let mut local = world_to_local_pixel(world_pos, transform, size, anchor)?;
+                    // `Sprite::flip_x`/`flip_y` mirror the rendered texture without touching the
+                    // transform, so the texel actually on screen at `local` is the mirror image of
+                    // what's stored in the source texture; mirror the sample point to match.
+                    if sprite.flip_x {
+                        local.x = size.x - local.x;
+                    }
+                    if sprite.flip_y {
+                        local.y = size.y - local.y;
+                    }
+
+                    if settings.alpha_test {
+                        let image = images.get(image_handle)?;
+                        // `local` is in the sprite's world-unit space (`size`, which is
+                        // `custom_size` when set); normalize it before scaling into `rect`'s
+                        // texture-pixel space, since the two only coincide when `custom_size`
+                        // is unset.
+                        let texel = local / size * rect.size();
+                        if sample_alpha(image, rect, texel) < settings.alpha_threshold {
+                            return None;
+                        }
+                    }
+
+                    let depth = -transform.translation().z;
+                    Some((
+                        entity,
+                        HitData::new(camera_entity, depth, Some(transform.translation()), Some(Vec3::Z)),
+                    ))
+                })
+                .collect();
+
+            hits.sort_by(|a, b| a.1.depth.partial_cmp(&b.1.depth).unwrap_or(std::cmp::Ordering::Equal));
+
+            if !hits.is_empty() {
+                output.send(PointerHits::new(pointer_id, hits, camera.order as f32));
+            }
+        }
+    }
+}
+
+/// Returns the sprite's world-space size and, if it's part of a texture atlas, the pixel `Rect`
+/// within the atlas texture that should be sampled for alpha.
+fn sprite_size_and_rect(
+    sprite: &Sprite,
+    atlas_sprite: Option<&TextureAtlasSprite>,
+    atlas_handle: Option<&Handle<TextureAtlas>>,
+    atlases: &Assets<TextureAtlas>,
+    images: &Assets<Image>,
+    image_handle: &Handle<Image>,
+) -> Option<(Vec2, Rect)> {
+    if let (Some(atlas_sprite), Some(atlas_handle)) = (atlas_sprite, atlas_handle) {
+        let atlas = atlases.get(atlas_handle)?;
+        let rect = atlas.textures.get(atlas_sprite.index)?;
+        let size = atlas_sprite.custom_size.unwrap_or_else(|| rect.size());
+        Some((size, *rect))
+    } else {
+        let image = images.get(image_handle)?;
+        let texture_size = image.size();
+        let size = sprite.custom_size.unwrap_or(texture_size);
+        Some((size, Rect::new(0.0, 0.0, texture_size.x, texture_size.y)))
+    }
+}
+
+/// Projects a world-space point onto the sprite's local pixel coordinates, measured from the
+/// top-left of its texture, or `None` if the point falls outside the sprite's bounds.
+fn world_to_local_pixel(
+    world_pos: Vec2,
+    transform: &GlobalTransform,
+    size: Vec2,
+    anchor: Vec2,
+) -> Option<Vec2> {
+    let local = transform
+        .compute_matrix()
+        .inverse()
+        .transform_point3(world_pos.extend(0.0))
+        .truncate();
+    // `local` is measured from the sprite's anchor; re-center it on the sprite, then flip Y to
+    // get pixel coordinates measured from the top-left of the texture.
+    let centered = local + anchor * size;
+    let pixel = Vec2::new(centered.x + size.x / 2.0, size.y / 2.0 - centered.y);
+    if pixel.x < 0.0 || pixel.y < 0.0 || pixel.x > size.x || pixel.y > size.y {
+        None
+    } else {
+        Some(pixel)
+    }
+}
+
+/// Samples the alpha channel of `image` at `local` pixel coordinates within `rect`.
+fn sample_alpha(image: &Image, rect: Rect, local: Vec2) -> f32 {
+    let x = (rect.min.x + local.x).floor().max(0.0) as u32;
+    let y = (rect.min.y + local.y).floor().max(0.0) as u32;
+    let width = image.texture_descriptor.size.width;
+    let height = image.texture_descriptor.size.height;
+    if x >= width || y >= height {
+        return 0.0;
+    }
+    let bytes_per_pixel = 4; // Rgba8
+    let index = ((y * width + x) * bytes_per_pixel) as usize;
+    image
+        .data
+        .get(index + 3)
+        .map(|alpha| *alpha as f32 / 255.0)
+        .unwrap_or(0.0)
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy_mod_picking::{
-    output::{Just, PointerInteractionEvent},
+    output::{EventData, EventFrom, IsPointerEvent},
+    prelude::*,
     DefaultPickingPlugins, PickRaycastSource, PickableBundle,
 };
 
@@ -9,38 +10,18 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(DefaultPickingPlugins) // <- Adds Picking, Interaction, and Highlighting plugins.
         .add_startup_system(setup)
-        .add_system_to_stage(CoreStage::PostUpdate, print_events)
+        .add_system(print_entered)
         .run();
 }
 
-pub fn print_events(mut events: EventReader<PointerInteractionEvent>) {
-    for interaction in events.iter() {
-        match interaction.event {
-            Just::Entered => info!(
-                "{:?} just entered {:?}",
-                interaction.id, interaction.pick_entity
-            ),
-            Just::Exited => info!(
-                "{:?} just exited {:?}",
-                interaction.id, interaction.pick_entity
-            ),
-            Just::Down => info!(
-                "{:?} just pressed down on {:?}",
-                interaction.id, interaction.pick_entity
-            ),
-            Just::Up => info!(
-                "{:?} just stopped pressing on {:?}",
-                interaction.id, interaction.pick_entity
-            ),
-            Just::Clicked => info!(
-                "{:?} just clicked {:?}",
-                interaction.id, interaction.pick_entity
-            ),
-            Just::Moved => info!(
-                "{:?} just moved over {:?}",
-                interaction.id, interaction.pick_entity
-            ),
-        }
+/// Forwarded whenever the pointer's dispatcher resolves an `Over` for the target entity. Events
+/// resolve in a fixed per-frame order (Cancel, the leave-side transitions, the enter-side
+/// transitions, Move, Down, DragStart, Drag, Up, Click, Drop, DragEnd). Forwarded events go to
+/// the hit entity itself only; they do not walk up the entity hierarchy to its ancestors.
+struct Entered(Entity);
+impl EventFrom for Entered {
+    fn new(event_data: &mut EventData<impl IsPointerEvent>) -> Self {
+        Self(event_data.target())
     }
 }
 
@@ -55,7 +36,9 @@ fn setup(
             material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
             ..Default::default()
         })
-        .insert_bundle(PickableBundle::default()); // <- Makes the mesh pickable.
+        .insert_bundle(PickableBundle::default()) // <- Makes the mesh pickable.
+        .insert(PickRaycastTarget::default()) // <- Marker for the raycast backend.
+        .forward_events::<PointerOver, Entered>();
     commands
         .spawn_bundle(PbrBundle {
             mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
@@ -63,7 +46,9 @@ fn setup(
             transform: Transform::from_xyz(0.0, 0.5, 0.0),
             ..Default::default()
         })
-        .insert_bundle(PickableBundle::default()); // <- Makes the mesh pickable.
+        .insert_bundle(PickableBundle::default()) // <- Makes the mesh pickable.
+        .insert(PickRaycastTarget::default()) // <- Marker for the raycast backend.
+        .forward_events::<PointerOver, Entered>();
     commands.spawn_bundle(PointLightBundle {
         point_light: PointLight {
             intensity: 1500.0,
@@ -80,3 +65,9 @@ fn setup(
         })
         .insert(PickRaycastSource::default()); // <- Sets the camera to use for picking.
 }
+
+fn print_entered(mut events: EventReader<Entered>) {
+    for Entered(entity) in events.iter() {
+        info!("{entity:?} was entered");
+    }
+}
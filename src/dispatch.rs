@@ -0,0 +1,112 @@
+//! The per-pointer, per-frame event dispatcher.
+//!
+//! Each interacted entity has one *effective* target per frame: the first entity, itself
+//! included, walking up its [`Parent`] chain whose policy is [`FocusPolicy::Block`] (or the
+//! topmost ancestor if none blocks). [`resolve_pointer_focus`] walks that hierarchy once per
+//! interacted entity and copies the
+//! hit entity's coarse [`Interaction`] onto the blocking ancestor, so anything already querying
+//! `Interaction` (buttons, `PickableBundle` consumers) sees the ancestor as hovered/pressed
+//! whenever a blocked descendant is. This does *not* bubble the discrete pointer output events
+//! (`PointerClick`, `PointerDrag`, etc.) - those are still dispatched to the literal hit entity
+//! only; see [`PointerFocus`]'s doc comment for the current scope. [`DispatchLabel`] gives the
+//! fixed, enforced ordering that [`crate::DebugEventsPlugin`] (and any other stage-ordered
+//! consumer) can rely on: `Cancel` before the leave-side transitions, before the enter-side
+//! transitions, before movement, before press/drag/release, before the events derived from a
+//! completed press.
+
+use bevy::{prelude::*, ui::FocusPolicy};
+
+/// Labels the fixed per-frame order pointer events resolve in: `Cancel` → the leave-side
+/// transitions (`DragLeave`, `Out`, `Leave`) → the enter-side transitions (`Over`, `Enter`,
+/// `DragEnter`) → movement (`Move`, `DragOver`) → press/drag (`Down`, `DragStart`, `Drag`) →
+/// release (`Up`, `Click`, `Drop`, `DragEnd`).
+///
+/// Systems in the same stage that need this order (e.g. [`crate::DebugEventsPlugin`]'s loggers)
+/// should `.label()` themselves with the matching variant and `.after()` the previous one, rather
+/// than relying on registration order, which Bevy does not guarantee.
+#[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DispatchLabel {
+    /// `PointerCancel`.
+    Cancel,
+    /// The leave-side transitions: `PointerDragLeave`, `PointerOut`, `PointerLeave`.
+    Leave,
+    /// The enter-side transitions: `PointerOver`, `PointerEnter`, `PointerDragEnter`.
+    Enter,
+    /// Movement: `PointerMove`, `PointerDragOver`.
+    Move,
+    /// Press and drag: `PointerDown`, `PointerDragStart`, `PointerDrag`.
+    Press,
+    /// Release and the events it derives: `PointerUp`, `PointerClick`, `PointerDrop`,
+    /// `PointerDragEnd`.
+    Release,
+}
+
+/// The entity a pointer's events should actually be considered to target this frame, after
+/// walking up the hit entity's [`Parent`] chain and stopping at the first ancestor (inclusive)
+/// whose [`FocusPolicy`] is [`FocusPolicy::Block`].
+///
+/// Entities with no [`FocusPolicy`] default to [`FocusPolicy::Pass`] and are skipped over, just
+/// like `bevy_ui`'s own focus resolution.
+///
+/// Current scope: [`resolve_pointer_focus`] copies the hit entity's coarse [`Interaction`] onto
+/// this entity, so `Interaction`-based consumers (buttons, `PickableBundle`) bubble correctly.
+/// The discrete forwarded pointer events (`PointerClick`, `PointerDrag`, `PointerDrop`, etc. via
+/// `forward_events`) are *not* redirected through `PointerFocus` - they still dispatch to the
+/// literal hit entity only, so an ancestor with a `forward_events` listener for a descendant's
+/// click/drag/drop will not receive it. `PointerFocus` itself is recorded for lookup by future
+/// consumers that need the resolved entity directly, but nothing in this crate reads it yet.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointerFocus(pub Entity);
+
+/// Walks every interacted entity's ancestors, records the blocking target in [`PointerFocus`], and
+/// - when that target is an ancestor rather than the entity itself - copies the entity's coarse
+/// [`Interaction`] onto it, so `Interaction`-based consumers (e.g. buttons, `PickableBundle`) see
+/// the ancestor as interacted whenever one of its descendants is. This does not redirect the
+/// discrete forwarded pointer events; see [`PointerFocus`]'s doc comment for what's and isn't
+/// covered.
+pub fn resolve_pointer_focus(
+    mut commands: Commands,
+    focus_policies: Query<&FocusPolicy>,
+    parents: Query<&Parent>,
+    mut interactions: Query<(Entity, &mut Interaction)>,
+) {
+    let hits: Vec<(Entity, Interaction)> = interactions
+        .iter()
+        .map(|(entity, &interaction)| (entity, interaction))
+        .collect();
+
+    for (entity, interaction) in hits {
+        if interaction == Interaction::None {
+            commands.entity(entity).remove::<PointerFocus>();
+            continue;
+        }
+
+        let focus = bubble_to_blocking_ancestor(entity, &focus_policies, &parents);
+        commands.entity(entity).insert(PointerFocus(focus));
+
+        if focus != entity {
+            if let Ok((_, mut ancestor_interaction)) = interactions.get_mut(focus) {
+                *ancestor_interaction = interaction;
+            }
+        }
+    }
+}
+
+/// Walks `entity`'s [`Parent`] chain, returning the first ancestor (inclusive of `entity` itself)
+/// whose [`FocusPolicy`] is [`FocusPolicy::Block`], or the topmost ancestor if none blocks.
+fn bubble_to_blocking_ancestor(
+    entity: Entity,
+    focus_policies: &Query<&FocusPolicy>,
+    parents: &Query<&Parent>,
+) -> Entity {
+    let mut current = entity;
+    loop {
+        if matches!(focus_policies.get(current), Ok(FocusPolicy::Block)) {
+            return current;
+        }
+        match parents.get(current) {
+            Ok(parent) => current = parent.get(),
+            Err(_) => return current,
+        }
+    }
+}
@@ -67,17 +67,22 @@
 #![allow(clippy::too_many_arguments)]
 #![deny(missing_docs)]
 
-use bevy::{app::PluginGroupBuilder, prelude::*, ui::FocusPolicy};
+use bevy::{app::PluginGroupBuilder, ecs::schedule::ShouldRun, prelude::*, ui::FocusPolicy};
+
+mod dispatch;
+pub use dispatch::{DispatchLabel, PointerFocus};
 
 // Re-exports
 pub use bevy_picking_core::{self as core, backend, focus, output, pointer};
-pub use bevy_picking_input as input;
+pub use bevy_picking_input::{self as input, PickingPluginsSettings};
 
 // Optional, feature-gated exports
 #[cfg(feature = "highlight")]
 pub use bevy_picking_highlight as highlight;
 #[cfg(feature = "selection")]
 pub use bevy_picking_selection as selection;
+#[cfg(feature = "drag")]
+pub use bevy_picking_drag as drag;
 
 /// Picking backend exports, feature-gated.
 pub mod backends {
@@ -87,6 +92,8 @@ pub mod backends {
     pub use bevy_picking_raycast as raycast;
     #[cfg(feature = "pick_shader")]
     pub use bevy_picking_shader as shader;
+    #[cfg(feature = "pick_sprite")]
+    pub use bevy_picking_sprite as sprite;
 }
 
 /// Common imports
@@ -99,7 +106,8 @@ pub mod prelude {
             PointerDragOver, PointerDragStart, PointerDrop, PointerEnter, PointerEventData,
             PointerLeave, PointerMove, PointerOut, PointerOver, PointerUp,
         },
-        DebugEventsPlugin, DefaultPickingPlugins, PickableBundle,
+        DebugEventsPlugin, DefaultPickingPlugins, DispatchLabel, PickableBundle,
+        PickingPluginsSettings, PointerFocus,
     };
 
     #[cfg(feature = "highlight")]
@@ -114,6 +122,9 @@ pub mod prelude {
         SelectionPlugin,
     };
 
+    #[cfg(feature = "drag")]
+    pub use crate::drag::{DragPlugin, Draggable, DropTarget};
+
     #[cfg(feature = "pick_raycast")]
     pub use crate::backends::raycast::{PickRaycastSource, PickRaycastTarget};
 
@@ -130,6 +141,9 @@ pub mod prelude {
 
         #[cfg(feature = "pick_shader")]
         pub use crate::backends::shader::ShaderPlugin;
+
+        #[cfg(feature = "pick_sprite")]
+        pub use crate::backends::sprite::{SpriteBackendSettings, SpritePlugin};
     }
 }
 
@@ -149,6 +163,12 @@ impl PluginGroup for DefaultPickingPlugins {
         group.add(selection::SelectionPlugin);
         #[cfg(feature = "highlight")]
         highlight::HighlightingPlugins.build(group);
+
+        // Picking backends, like the `mod_picking` raycast backend, are opt-in: add the
+        // one(s) you need from `prelude::backends` yourself. `SpritePlugin` and `DragPlugin`
+        // follow that same convention rather than being force-added here, so enabling the
+        // `pick_sprite`/`drag` features doesn't silently turn on sprite hit-testing or
+        // drag-and-drop for every app that links the crate.
     }
 }
 
@@ -203,11 +223,12 @@ impl PointerBundle {
     }
 }
 
-/// Adds default mouse and touch pointers to your app.
+/// Adds default mouse, touch, and gamepad pointers to your app.
 pub struct DefaultPointersPlugin;
 impl Plugin for DefaultPointersPlugin {
     fn build(&self, app: &mut App) {
-        app.add_startup_system(add_default_pointers);
+        app.add_startup_system(add_default_pointers)
+            .add_system(spawn_gamepad_pointers);
     }
 }
 
@@ -220,38 +241,140 @@ pub fn add_default_pointers(mut commands: Commands) {
     }
 }
 
+/// Spawns a `Custom` software cursor pointer, with [`input::GamepadPointerSettings`] driving it,
+/// for every gamepad that connects, so controllers get the same picking pipeline mouse and touch
+/// pointers use.
+pub fn spawn_gamepad_pointers(
+    mut commands: Commands,
+    mut gamepad_events: EventReader<GamepadEvent>,
+) {
+    for event in gamepad_events.iter() {
+        if let GamepadEventType::Connected(_) = event.event_type {
+            commands
+                .spawn_bundle(PointerBundle::new(pointer::PointerId::Custom(
+                    bevy::utils::Uuid::new_v4(),
+                )))
+                .insert(input::GamepadPointerSettings {
+                    gamepad: event.gamepad,
+                    ..Default::default()
+                });
+        }
+    }
+}
+
 /// Logs events for debugging
 pub struct DebugEventsPlugin;
 impl Plugin for DebugEventsPlugin {
     fn build(&self, app: &mut App) {
         use bevy_picking_core::event_debug;
 
+        // Bubbles each interacted entity's `Interaction` up to its blocking ancestor (and records
+        // the result in `PointerFocus`) before anything reads it this frame, walking the `Parent`
+        // hierarchy and stopping at the first `FocusPolicy::Block`. Gated on `events_enabled` like
+        // the dispatch-ordered loggers below, so disabling events also stops this from mutating
+        // `Interaction`/`PointerFocus` every frame.
         app.add_system_set_to_stage(
             CoreStage::PreUpdate,
             SystemSet::new()
-                .with_system(event_debug::<output::PointerOver>)
-                .with_system(event_debug::<output::PointerOut>)
-                .with_system(event_debug::<output::PointerEnter>)
-                .with_system(event_debug::<output::PointerLeave>)
-                .with_system(event_debug::<output::PointerDown>)
-                .with_system(event_debug::<output::PointerUp>)
-                .with_system(event_debug::<output::PointerClick>)
+                .with_run_criteria(events_enabled)
+                .with_system(dispatch::resolve_pointer_focus),
+        );
+
+        // Labeled (not just registered) in the canonical per-pointer, per-frame order the
+        // dispatcher resolves them in, and chained with `.after()` so Bevy actually enforces it
+        // rather than relying on registration order.
+        app.add_system_set_to_stage(
+            CoreStage::PreUpdate,
+            SystemSet::new()
+                .with_run_criteria(events_enabled)
+                .with_system(
+                    event_debug::<output::PointerCancel>.label(DispatchLabel::Cancel),
+                )
+                .with_system(
+                    event_debug::<output::PointerDragLeave>
+                        .label(DispatchLabel::Leave)
+                        .after(DispatchLabel::Cancel),
+                )
+                .with_system(
+                    event_debug::<output::PointerOut>
+                        .label(DispatchLabel::Leave)
+                        .after(DispatchLabel::Cancel),
+                )
+                .with_system(
+                    event_debug::<output::PointerLeave>
+                        .label(DispatchLabel::Leave)
+                        .after(DispatchLabel::Cancel),
+                )
+                .with_system(
+                    event_debug::<output::PointerOver>
+                        .label(DispatchLabel::Enter)
+                        .after(DispatchLabel::Leave),
+                )
+                .with_system(
+                    event_debug::<output::PointerEnter>
+                        .label(DispatchLabel::Enter)
+                        .after(DispatchLabel::Leave),
+                )
+                .with_system(
+                    event_debug::<output::PointerDragEnter>
+                        .label(DispatchLabel::Enter)
+                        .after(DispatchLabel::Leave),
+                )
                 //.with_system(event_debug::<output::PointerMove>)
-                .with_system(event_debug::<output::PointerCancel>)
-                .with_system(event_debug::<output::PointerDragStart>)
+                .with_system(
+                    event_debug::<output::PointerDragOver>
+                        .label(DispatchLabel::Move)
+                        .after(DispatchLabel::Enter),
+                )
+                .with_system(
+                    event_debug::<output::PointerDown>
+                        .label(DispatchLabel::Press)
+                        .after(DispatchLabel::Move),
+                )
+                .with_system(
+                    event_debug::<output::PointerDragStart>
+                        .label(DispatchLabel::Press)
+                        .after(DispatchLabel::Move),
+                )
                 //.with_system(event_debug::<output::PointerDrag>)
-                .with_system(event_debug::<output::PointerDragEnd>)
-                .with_system(event_debug::<output::PointerDragEnter>)
-                .with_system(event_debug::<output::PointerDragOver>)
-                .with_system(event_debug::<output::PointerDragLeave>)
-                .with_system(event_debug::<output::PointerDrop>),
+                .with_system(
+                    event_debug::<output::PointerUp>
+                        .label(DispatchLabel::Release)
+                        .after(DispatchLabel::Press),
+                )
+                .with_system(
+                    event_debug::<output::PointerClick>
+                        .label(DispatchLabel::Release)
+                        .after(DispatchLabel::Press),
+                )
+                .with_system(
+                    event_debug::<output::PointerDrop>
+                        .label(DispatchLabel::Release)
+                        .after(DispatchLabel::Press),
+                )
+                .with_system(
+                    event_debug::<output::PointerDragEnd>
+                        .label(DispatchLabel::Release)
+                        .after(DispatchLabel::Press),
+                ),
         );
         #[cfg(feature = "selection")]
         app.add_system_set_to_stage(
             CoreStage::PreUpdate,
             SystemSet::new()
+                .with_run_criteria(events_enabled)
                 .with_system(event_debug::<selection::PointerSelect>)
                 .with_system(event_debug::<selection::PointerDeselect>),
         );
     }
 }
+
+/// Run criteria that skips dispatch-dependent systems while
+/// [`PickingPluginsSettings::enable_events`] is `false`.
+fn events_enabled(settings: Res<PickingPluginsSettings>) -> ShouldRun {
+    if settings.enable_events {
+        ShouldRun::Yes
+    } else {
+        ShouldRun::No
+    }
+}